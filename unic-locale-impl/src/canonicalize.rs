@@ -0,0 +1,45 @@
+//! Canonicalization of Unicode extension keyword values.
+//!
+//! BCP-47/CLDR define a number of deprecated Unicode extension type aliases
+//! that canonicalization must resolve to their preferred form, e.g. the
+//! legacy `u-ca-islamicc` is the alias for `u-ca-islamic-civil`, and the
+//! multi-subtag `u-ca-ethiopic-amete-alem` is the alias for `u-ca-ethioaa`.
+//! The table below is a small, hand-curated subset of CLDR's `bcp47` type
+//! alias data (not machine-generated from the CLDR source XML), keyed first
+//! by Unicode extension key and then by the full deprecated value (its
+//! subtags joined with `-`, however many there are), so a lookup is two
+//! compile-time-generated map probes. Either side of an alias may span more
+//! than one subtag, so both the lookup key and the replacement are taken
+//! from (and produce) the whole value subtag sequence, not a single subtag.
+use tinystr::TinyStr8;
+
+use crate::extensions::UnicodeExtensionKey;
+
+static UNICODE_TYPE_ALIASES: phf::Map<&'static str, phf::Map<&'static str, &'static [&'static str]>> = phf::phf_map! {
+    "ca" => phf::phf_map! {
+        "islamicc" => &["islamic", "civil"],
+        "ethiopic-amete-alem" => &["ethioaa"],
+    },
+    "tz" => phf::phf_map! {
+        "aqams" => &["nzakl"],
+        "cyprus" => &["cyath"],
+    },
+};
+
+/// Returns the canonical replacement subtags for a Unicode extension
+/// keyword `key` whose full value is `values` (its subtags, in order), if
+/// that whole value sequence is a known deprecated alias.
+pub(crate) fn canonical_unicode_value(
+    key: UnicodeExtensionKey,
+    values: &[TinyStr8],
+) -> Option<&'static [&'static str]> {
+    let joined = values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("-");
+    UNICODE_TYPE_ALIASES
+        .get(key.as_str())?
+        .get(joined.as_str())
+        .copied()
+}
@@ -0,0 +1,37 @@
+use std::error::Error;
+use std::fmt;
+
+use unic_langid_impl::LanguageIdentifierError;
+
+use crate::parser::ParserError;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LocaleError {
+    Unknown,
+    ParserError(ParserError),
+    LanguageIdentifierError(LanguageIdentifierError),
+}
+
+impl fmt::Display for LocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LocaleError::Unknown => f.write_str("Unknown error"),
+            LocaleError::ParserError(error) => error.fmt(f),
+            LocaleError::LanguageIdentifierError(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for LocaleError {}
+
+impl From<ParserError> for LocaleError {
+    fn from(error: ParserError) -> Self {
+        LocaleError::ParserError(error)
+    }
+}
+
+impl From<LanguageIdentifierError> for LocaleError {
+    fn from(error: LanguageIdentifierError) -> Self {
+        LocaleError::LanguageIdentifierError(error)
+    }
+}
@@ -8,12 +8,12 @@
 //!  * Private Use Extensions - marked as `x`.
 //!  * Other extensions - marked as any `a-z` except of `u`, `t` and `x`.
 mod private;
-mod transform;
-mod unicode;
+pub(crate) mod transform;
+pub(crate) mod unicode;
 
 pub use private::PrivateExtensionList;
 pub use transform::TransformExtensionList;
-pub use unicode::UnicodeExtensionList;
+pub use unicode::{UnicodeExtensionKey, UnicodeExtensionList};
 
 use std::collections::BTreeMap;
 use std::fmt::Write;
@@ -22,6 +22,7 @@ use std::str::FromStr;
 
 use tinystr::TinyStr8;
 
+use crate::errors::LocaleError;
 use crate::parser::ParserError;
 
 /// Defines the type of extension.
@@ -50,6 +51,15 @@ impl ExtensionType {
     }
 }
 
+impl FromStr for ExtensionType {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let byte = source.as_bytes().first().ok_or(ParserError::InvalidExtension)?;
+        ExtensionType::from_byte(*byte)
+    }
+}
+
 impl std::fmt::Display for ExtensionType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let ch = match self {
@@ -94,8 +104,21 @@ impl ExtensionsMap {
                 Some(Ok(ExtensionType::Private)) => {
                     result.private = PrivateExtensionList::try_from_iter(iter)?;
                 }
+                Some(Ok(ExtensionType::Other(c))) => {
+                    let mut subtags = Vec::new();
+                    while let Some(subtag) = iter.peek() {
+                        if subtag.len() == 1 {
+                            break;
+                        }
+                        let subtag = iter.next().unwrap();
+                        let subtag = TinyStr8::from_bytes(subtag)
+                            .map_err(|_| ParserError::InvalidExtension)?;
+                        subtags.push(subtag);
+                    }
+                    result.other.insert(c, subtags);
+                }
                 None => {}
-                _ => unimplemented!(),
+                Some(Err(e)) => return Err(e),
             }
 
             st = iter.next();
@@ -105,7 +128,52 @@ impl ExtensionsMap {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.unicode.is_empty() && self.transform.is_empty() && self.private.is_empty()
+        self.unicode.is_empty()
+            && self.transform.is_empty()
+            && self.private.is_empty()
+            && self.other.is_empty()
+    }
+
+    /// Canonicalizes the extensions in place: lowercases every extension
+    /// singleton and subtag (except the transform `tlang`, which keeps its
+    /// `LanguageIdentifier` casing) and resolves deprecated CLDR Unicode
+    /// keyword/type aliases. Singleton and subtag ordering is already
+    /// canonical by construction (`BTreeMap`/`BTreeSet` storage), so this
+    /// is idempotent.
+    pub(crate) fn canonicalize(&mut self) {
+        self.unicode.canonicalize();
+        self.transform.canonicalize();
+        self.private.canonicalize();
+
+        let other = std::mem::take(&mut self.other);
+        for (singleton, subtags) in other {
+            let subtags = subtags
+                .into_iter()
+                .map(|subtag| subtag.to_ascii_lowercase())
+                .collect();
+            self.other
+                .insert(singleton.to_ascii_lowercase(), subtags);
+        }
+    }
+
+    pub fn get_other_value(&self, singleton: char) -> Option<&[TinyStr8]> {
+        self.other.get(&singleton).map(Vec::as_slice)
+    }
+
+    pub fn set_other_value(
+        &mut self,
+        singleton: char,
+        key: &str,
+        value: Option<&str>,
+    ) -> Result<(), LocaleError> {
+        let key: TinyStr8 = key.parse().map_err(|_| LocaleError::Unknown)?;
+        let entry = self.other.entry(singleton).or_insert_with(Vec::new);
+        entry.push(key);
+        if let Some(value) = value {
+            let value: TinyStr8 = value.parse().map_err(|_| LocaleError::Unknown)?;
+            entry.push(value);
+        }
+        Ok(())
     }
 }
 
@@ -119,9 +187,68 @@ impl FromStr for ExtensionsMap {
 
 impl std::fmt::Display for ExtensionsMap {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        // Alphabetic by singleton (t, u, x)
+        // Canonical singleton order: other extensions (a-z except t, u, x,
+        // sorted by singleton), then t, then u, then x.
+        for (singleton, subtags) in &self.other {
+            write!(f, "-{}", singleton)?;
+            for subtag in subtags {
+                write!(f, "-{}", subtag)?;
+            }
+        }
         write!(f, "{}{}{}", self.transform, self.unicode, self.private)?;
 
         Ok(())
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExtensionsMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExtensionsMap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let source = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        source.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExtensionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExtensionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let source = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        source.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExtensionsMap;
+
+    #[test]
+    fn malformed_singleton_is_an_error_not_a_panic() {
+        assert!("!-foo".parse::<ExtensionsMap>().is_err());
+    }
+}
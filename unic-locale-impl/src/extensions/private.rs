@@ -0,0 +1,77 @@
+//! Private Use Extensions - marked as `x`.
+//!
+//! Private use extensions carry an ordered list of subtags whose meaning is
+//! not standardized, e.g. `en-US-x-twain`.
+use std::iter::Peekable;
+
+use tinystr::TinyStr8;
+
+use super::ExtensionsMap;
+use crate::errors::LocaleError;
+use crate::parser::ParserError;
+
+/// A list of Private Use extension subtags associated with a given `Locale`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct PrivateExtensionList {
+    tags: Vec<TinyStr8>,
+}
+
+impl PrivateExtensionList {
+    pub(crate) fn try_from_iter<'a>(
+        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    ) -> Result<Self, ParserError> {
+        let mut tags = Vec::new();
+
+        while let Some(subtag) = iter.peek() {
+            if subtag.len() == 1 {
+                break;
+            }
+            let subtag = iter.next().unwrap();
+            let subtag = std::str::from_utf8(subtag).map_err(|_| ParserError::InvalidExtension)?;
+            let tag: TinyStr8 = subtag.parse().map_err(|_| ParserError::InvalidExtension)?;
+            tags.push(tag);
+        }
+
+        Ok(Self { tags })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Lowercases all subtags.
+    pub(crate) fn canonicalize(&mut self) {
+        for tag in self.tags.iter_mut() {
+            *tag = tag.to_ascii_lowercase();
+        }
+    }
+}
+
+impl std::fmt::Display for PrivateExtensionList {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        write!(f, "-x")?;
+        for tag in &self.tags {
+            write!(f, "-{}", tag)?;
+        }
+        Ok(())
+    }
+}
+
+impl ExtensionsMap {
+    pub fn get_private(&self) -> &[TinyStr8] {
+        &self.private.tags
+    }
+
+    pub fn set_private_value(&mut self, key: &str, value: Option<&str>) -> Result<(), LocaleError> {
+        let tag: TinyStr8 = key.parse().map_err(|_| LocaleError::Unknown)?;
+        self.private.tags.push(tag);
+        if let Some(value) = value {
+            let value: TinyStr8 = value.parse().map_err(|_| LocaleError::Unknown)?;
+            self.private.tags.push(value);
+        }
+        Ok(())
+    }
+}
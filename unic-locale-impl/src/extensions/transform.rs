@@ -0,0 +1,151 @@
+//! Transform Extensions - marked as `t`.
+//!
+//! Transform extensions carry an optional source `LanguageIdentifier`
+//! ("tlang"), followed by a list of two-character field keys, each paired
+//! with one or more value subtags, e.g. `en-US-t-es-AR-h0-hybrid`.
+use std::collections::BTreeMap;
+use std::iter::Peekable;
+
+use tinystr::TinyStr8;
+use unic_langid_impl::LanguageIdentifier;
+
+use super::ExtensionsMap;
+use crate::errors::LocaleError;
+use crate::parser::ParserError;
+
+/// Returns `true` if `subtag` is a valid Transform extension field key: two
+/// characters where the first is alphabetic and the second is a digit
+/// (e.g. `h0`, `s0`, `d0`).
+pub(crate) fn is_field_key(subtag: &str) -> bool {
+    let bytes = subtag.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1].is_ascii_digit()
+}
+
+/// A list of Transform extension fields associated with a given `Locale`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct TransformExtensionList {
+    tlang: Option<LanguageIdentifier>,
+    fields: BTreeMap<TinyStr8, Vec<TinyStr8>>,
+}
+
+impl TransformExtensionList {
+    pub(crate) fn try_from_iter<'a>(
+        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    ) -> Result<Self, ParserError> {
+        let mut tlang_subtags = Vec::new();
+        while let Some(subtag) = iter.peek() {
+            if subtag.len() == 1 {
+                break;
+            }
+            let s = std::str::from_utf8(subtag).map_err(|_| ParserError::InvalidExtension)?;
+            if is_field_key(s) {
+                break;
+            }
+            tlang_subtags.push(iter.next().unwrap());
+        }
+        let tlang = if tlang_subtags.is_empty() {
+            None
+        } else {
+            Some(parse_tlang(&tlang_subtags)?)
+        };
+
+        let mut fields = BTreeMap::new();
+        let mut current_key: Option<TinyStr8> = None;
+
+        while let Some(subtag) = iter.peek() {
+            if subtag.len() == 1 {
+                break;
+            }
+            let subtag = iter.next().unwrap();
+            let subtag = std::str::from_utf8(subtag).map_err(|_| ParserError::InvalidExtension)?;
+
+            if is_field_key(subtag) {
+                let key: TinyStr8 = subtag.parse().map_err(|_| ParserError::InvalidExtension)?;
+                if let Some(key) = current_key.replace(key) {
+                    fields.entry(key).or_insert_with(Vec::new);
+                }
+            } else if let Some(key) = current_key {
+                let value: TinyStr8 = subtag.parse().map_err(|_| ParserError::InvalidExtension)?;
+                fields.entry(key).or_insert_with(Vec::new).push(value);
+            } else {
+                return Err(ParserError::InvalidExtension);
+            }
+        }
+        if let Some(key) = current_key {
+            fields.entry(key).or_insert_with(Vec::new);
+        }
+
+        Ok(Self { tlang, fields })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tlang.is_none() && self.fields.is_empty()
+    }
+
+    /// Lowercases all field keys and values. `tlang`, if present, is already
+    /// kept in its own canonical casing by `LanguageIdentifier`.
+    pub(crate) fn canonicalize(&mut self) {
+        let fields = std::mem::take(&mut self.fields);
+        for (key, values) in fields {
+            let values = values
+                .into_iter()
+                .map(|value| value.to_ascii_lowercase())
+                .collect();
+            self.fields.insert(key.to_ascii_lowercase(), values);
+        }
+    }
+}
+
+fn parse_tlang(subtags: &[&[u8]]) -> Result<LanguageIdentifier, ParserError> {
+    let joined = subtags.join(&b'-');
+    let source = std::str::from_utf8(&joined).map_err(|_| ParserError::InvalidLanguage)?;
+    source.parse().map_err(|_| ParserError::InvalidLanguage)
+}
+
+impl std::fmt::Display for TransformExtensionList {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        write!(f, "-t")?;
+        if let Some(tlang) = &self.tlang {
+            write!(f, "-{}", tlang)?;
+        }
+        for (key, value) in &self.fields {
+            write!(f, "-{}", key)?;
+            for subtag in value {
+                write!(f, "-{}", subtag)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ExtensionsMap {
+    pub fn get_transform_value(&self, key: &str) -> Option<&[TinyStr8]> {
+        let key: TinyStr8 = key.parse().ok()?;
+        self.transform.fields.get(&key).map(Vec::as_slice)
+    }
+
+    pub fn set_transform_value(
+        &mut self,
+        key: &str,
+        value: Option<&str>,
+    ) -> Result<(), LocaleError> {
+        let key: TinyStr8 = key.parse().map_err(|_| LocaleError::Unknown)?;
+        let entry = self.transform.fields.entry(key).or_insert_with(Vec::new);
+        if let Some(value) = value {
+            let value: TinyStr8 = value.parse().map_err(|_| LocaleError::Unknown)?;
+            entry.push(value);
+        }
+        Ok(())
+    }
+
+    pub fn get_tlang(&self) -> Option<&LanguageIdentifier> {
+        self.transform.tlang.as_ref()
+    }
+
+    pub fn set_tlang(&mut self, tlang: Option<LanguageIdentifier>) {
+        self.transform.tlang = tlang;
+    }
+}
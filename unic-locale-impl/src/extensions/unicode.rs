@@ -0,0 +1,181 @@
+//! Unicode Extensions - marked as `u`.
+//!
+//! Unicode extensions carry a list of two-character keywords, each with an
+//! optional type made of one or more subtags, e.g. `en-u-ca-buddhist`.
+use std::collections::{BTreeMap, BTreeSet};
+use std::iter::Peekable;
+use std::str::FromStr;
+
+use tinystr::{TinyStr4, TinyStr8};
+
+use super::ExtensionsMap;
+use crate::errors::LocaleError;
+use crate::parser::ParserError;
+
+/// A key of a Unicode extension keyword, e.g. `ca` in `u-ca-buddhist`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct UnicodeExtensionKey(TinyStr4);
+
+impl FromStr for UnicodeExtensionKey {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let key = source.as_bytes();
+        if key.len() != 2 || !key[0].is_ascii_alphanumeric() || !key[1].is_ascii_alphanumeric() {
+            return Err(ParserError::InvalidExtension);
+        }
+        let key = TinyStr4::from_bytes(key).map_err(|_| ParserError::InvalidExtension)?;
+        Ok(Self(key.to_ascii_lowercase()))
+    }
+}
+
+impl std::fmt::Display for UnicodeExtensionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl UnicodeExtensionKey {
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Returns `true` if `subtag` is shaped like a Unicode extension attribute:
+/// an alphanumeric subtag of 3 to 8 characters that comes before any key.
+pub(crate) fn is_attribute(subtag: &str) -> bool {
+    (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+/// A list of Unicode extension attributes and keywords associated with a
+/// given `Locale`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct UnicodeExtensionList {
+    attributes: BTreeSet<TinyStr8>,
+    keywords: BTreeMap<UnicodeExtensionKey, Vec<TinyStr8>>,
+}
+
+impl UnicodeExtensionList {
+    pub(crate) fn try_from_iter<'a>(
+        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    ) -> Result<Self, ParserError> {
+        let mut attributes = BTreeSet::new();
+        while let Some(subtag) = iter.peek() {
+            let s = std::str::from_utf8(subtag).map_err(|_| ParserError::InvalidExtension)?;
+            if !is_attribute(s) {
+                break;
+            }
+            let attribute: TinyStr8 = s.parse().map_err(|_| ParserError::InvalidExtension)?;
+            attributes.insert(attribute);
+            iter.next();
+        }
+
+        let mut keywords = BTreeMap::new();
+        let mut current_key: Option<UnicodeExtensionKey> = None;
+
+        while let Some(subtag) = iter.peek() {
+            if subtag.len() == 1 {
+                break;
+            }
+            let subtag = iter.next().unwrap();
+            let subtag = std::str::from_utf8(subtag).map_err(|_| ParserError::InvalidExtension)?;
+
+            if subtag.len() == 2 {
+                if let Some(key) = current_key.replace(subtag.parse()?) {
+                    keywords.entry(key).or_insert_with(Vec::new);
+                }
+            } else if let Some(key) = current_key {
+                let value: TinyStr8 = subtag.parse().map_err(|_| ParserError::InvalidExtension)?;
+                keywords.entry(key).or_insert_with(Vec::new).push(value);
+            } else {
+                return Err(ParserError::InvalidExtension);
+            }
+        }
+        if let Some(key) = current_key {
+            keywords.entry(key).or_insert_with(Vec::new);
+        }
+
+        Ok(Self {
+            attributes,
+            keywords,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.keywords.is_empty()
+    }
+
+    /// Lowercases all attributes and keyword values, resolves deprecated
+    /// CLDR type aliases to their preferred value, and drops `true`-valued
+    /// keywords to their bare key, as BCP-47 requires. Keyword keys are
+    /// already guaranteed lowercase by `UnicodeExtensionKey::from_str`.
+    pub(crate) fn canonicalize(&mut self) {
+        self.attributes = std::mem::take(&mut self.attributes)
+            .into_iter()
+            .map(|attribute| attribute.to_ascii_lowercase())
+            .collect();
+
+        for (key, values) in self.keywords.iter_mut() {
+            for value in values.iter_mut() {
+                *value = value.to_ascii_lowercase();
+            }
+            if let Some(canonical) = crate::canonicalize::canonical_unicode_value(*key, values) {
+                *values = canonical
+                    .iter()
+                    .map(|subtag| subtag.parse().expect("alias table entries are valid subtags"))
+                    .collect();
+            }
+            if values.len() == 1 && values[0].to_string() == "true" {
+                values.clear();
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for UnicodeExtensionList {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        write!(f, "-u")?;
+        for attribute in &self.attributes {
+            write!(f, "-{}", attribute)?;
+        }
+        for (key, value) in &self.keywords {
+            write!(f, "-{}", key)?;
+            for subtag in value {
+                write!(f, "-{}", subtag)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ExtensionsMap {
+    pub fn get_unicode_value(&self, key: UnicodeExtensionKey) -> Option<&[TinyStr8]> {
+        self.unicode.keywords.get(&key).map(Vec::as_slice)
+    }
+
+    pub fn set_unicode_value(
+        &mut self,
+        key: UnicodeExtensionKey,
+        value: Option<&str>,
+    ) -> Result<(), LocaleError> {
+        let entry = self.unicode.keywords.entry(key).or_insert_with(Vec::new);
+        if let Some(value) = value {
+            let value: TinyStr8 = value.parse().map_err(|_| LocaleError::Unknown)?;
+            entry.push(value);
+        }
+        Ok(())
+    }
+
+    pub fn get_unicode_attributes(&self) -> impl Iterator<Item = &TinyStr8> {
+        self.unicode.attributes.iter()
+    }
+
+    pub fn set_unicode_attribute(&mut self, attribute: &str) -> Result<(), LocaleError> {
+        let attribute: TinyStr8 = attribute.parse().map_err(|_| LocaleError::Unknown)?;
+        self.unicode.attributes.insert(attribute);
+        Ok(())
+    }
+}
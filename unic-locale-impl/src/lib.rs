@@ -1,3 +1,4 @@
+mod canonicalize;
 pub mod errors;
 pub mod extensions;
 pub mod parser;
@@ -6,6 +7,8 @@ use errors::LocaleError;
 pub use extensions::{ExtensionType, ExtensionsMap, UnicodeExtensionKey};
 use std::str::FromStr;
 use tinystr::{TinyStr4, TinyStr8};
+// `LanguageIdentifier`'s `serde` impls live upstream in `unic_langid_impl`
+// and are pulled in by forwarding this crate's `serde` feature to it.
 pub use unic_langid_impl::LanguageIdentifier;
 
 #[derive(Debug, Default, PartialEq, Clone)]
@@ -114,13 +117,26 @@ impl Locale {
                 let k = UnicodeExtensionKey::from_str(key)?;
                 self.extensions.set_unicode_value(k, value)
             }
-            _ => unimplemented!(),
+            ExtensionType::Transform => self.extensions.set_transform_value(key, value),
+            ExtensionType::Private => self.extensions.set_private_value(key, value),
+            ExtensionType::Other(c) => self.extensions.set_other_value(c, key, value),
         }
     }
 
     pub fn get_extensions(&self) -> &extensions::ExtensionsMap {
         &self.extensions
     }
+
+    /// Returns a canonicalized copy of this `Locale` per BCP-47/CLDR rules:
+    /// deprecated Unicode extension keyword/type aliases are resolved to
+    /// their preferred form and `true`-valued keywords are dropped to their
+    /// bare key. Base subtag casing, singleton ordering and subtag sorting
+    /// are already canonical by construction, so this pass is idempotent.
+    pub fn canonicalize(&self) -> Self {
+        let mut result = self.clone();
+        result.extensions.canonicalize();
+        result
+    }
 }
 
 impl FromStr for Locale {
@@ -160,17 +176,184 @@ impl AsRef<Locale> for Locale {
 
 impl std::fmt::Display for Locale {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let mut subtags = vec![self.langid.to_string()];
-        let ext = self.extensions.to_string();
-
-        if !ext.is_empty() {
-            subtags.push(ext);
-        }
-        write!(f, "{}", subtags.join("-"))
+        // `self.extensions` already emits its own leading `-` for every
+        // singleton it writes (or nothing at all when empty), so no
+        // separator belongs between it and the langid here.
+        write!(f, "{}{}", self.langid, self.extensions)
     }
 }
 
 pub fn canonicalize(input: &str) -> Result<String, LocaleError> {
     let locale: Locale = input.parse()?;
-    Ok(locale.to_string())
+    Ok(locale.canonicalize().to_string())
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let source = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        source.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locale;
+
+    #[test]
+    fn other_extension_roundtrips() {
+        let locale: Locale = "en-a-foo-bar-u-ca-buddhist".parse().unwrap();
+        assert_eq!(locale.to_string(), "en-a-foo-bar-u-ca-buddhist");
+    }
+
+    #[test]
+    fn transform_tlang_roundtrips() {
+        let locale: Locale = "en-US-t-es-ar-h0-hybrid".parse().unwrap();
+        assert_eq!(locale.to_string(), "en-US-t-es-AR-h0-hybrid");
+    }
+
+    #[test]
+    fn transform_field_keeps_all_value_subtags() {
+        let locale: Locale = "und-t-es-ar-h0-hybrid-olden1".parse().unwrap();
+        assert_eq!(
+            locale.extensions.get_transform_value("h0").unwrap(),
+            &["hybrid".parse().unwrap(), "olden1".parse().unwrap()][..]
+        );
+        assert_eq!(
+            locale.extensions.get_tlang().unwrap().to_string(),
+            "es-AR"
+        );
+    }
+
+    #[test]
+    fn unicode_attribute_roundtrips() {
+        let locale: Locale = "en-u-foobar-ca-buddhist".parse().unwrap();
+        assert_eq!(locale.to_string(), "en-u-foobar-ca-buddhist");
+    }
+
+    #[test]
+    fn canonicalize_resolves_deprecated_unicode_alias() {
+        let locale: Locale = "en-u-ca-islamicc".parse().unwrap();
+        assert_eq!(locale.canonicalize().to_string(), "en-u-ca-islamic-civil");
+    }
+
+    #[test]
+    fn canonicalize_lowercases_extensions_built_via_the_mutation_api() {
+        use crate::extensions::ExtensionType;
+
+        // Built through the public mutation API rather than parsed, so none
+        // of this casing has already passed through `parse_locale`'s
+        // upfront lowercasing of the extension string.
+        let mut locale = Locale::default();
+        locale.set_language(Some("en")).unwrap();
+        locale
+            .set_extension(ExtensionType::Other('A'), "FOO", None)
+            .unwrap();
+        locale
+            .set_extension(ExtensionType::Transform, "H0", Some("HYBRID"))
+            .unwrap();
+        locale
+            .set_extension(ExtensionType::Private, "TWAIN", None)
+            .unwrap();
+
+        assert_eq!(
+            locale.canonicalize().to_string(),
+            "en-a-foo-t-h0-hybrid-x-twain"
+        );
+    }
+
+    #[test]
+    fn canonicalize_resolves_multi_subtag_deprecated_unicode_alias() {
+        let locale: Locale = "en-u-ca-ethiopic-amete-alem".parse().unwrap();
+        assert_eq!(locale.canonicalize().to_string(), "en-u-ca-ethioaa");
+    }
+
+    #[test]
+    fn canonicalize_resolves_tz_aliases() {
+        let aqams: Locale = "en-u-tz-aqams".parse().unwrap();
+        assert_eq!(aqams.canonicalize().to_string(), "en-u-tz-nzakl");
+
+        let cyprus: Locale = "en-u-tz-cyprus".parse().unwrap();
+        assert_eq!(cyprus.canonicalize().to_string(), "en-u-tz-cyath");
+    }
+
+    #[test]
+    fn canonicalize_drops_true_valued_keyword_to_its_bare_key() {
+        let locale: Locale = "en-u-kn-true".parse().unwrap();
+        assert_eq!(locale.canonicalize().to_string(), "en-u-kn");
+    }
+
+    #[test]
+    fn canonicalize_is_idempotent() {
+        let locale: Locale = "en-U-KN-TRUE-CA-ISLAMICC-A-FOO".parse().unwrap();
+        let once = locale.canonicalize();
+        let twice = once.canonicalize();
+        assert_eq!(once, twice);
+    }
+
+    #[test]
+    fn set_extension_roundtrips_for_transform_private_and_other() {
+        use crate::extensions::ExtensionType;
+
+        let mut locale = Locale::default();
+        locale.set_language(Some("en")).unwrap();
+
+        locale
+            .set_extension(ExtensionType::Transform, "h0", Some("hybrid"))
+            .unwrap();
+        assert_eq!(
+            locale.extensions.get_transform_value("h0").unwrap(),
+            &["hybrid".parse().unwrap()][..]
+        );
+
+        locale
+            .set_extension(ExtensionType::Private, "twain", None)
+            .unwrap();
+        assert_eq!(
+            locale.extensions.get_private(),
+            &["twain".parse().unwrap()][..]
+        );
+
+        locale
+            .set_extension(ExtensionType::Other('a'), "foo", Some("bar"))
+            .unwrap();
+        assert_eq!(
+            locale.extensions.get_other_value('a').unwrap(),
+            &["foo".parse().unwrap(), "bar".parse().unwrap()][..]
+        );
+    }
+
+    #[test]
+    fn set_extension_returns_err_on_invalid_key_instead_of_panicking() {
+        use crate::extensions::ExtensionType;
+
+        let mut locale = Locale::default();
+        locale.set_language(Some("en")).unwrap();
+
+        assert!(locale
+            .set_extension(ExtensionType::Unicode, "toolong", None)
+            .is_err());
+        assert!(locale
+            .set_extension(ExtensionType::Transform, "waytoolongforatag", None)
+            .is_err());
+        assert!(locale
+            .set_extension(ExtensionType::Private, "waytoolongforatag", None)
+            .is_err());
+        assert!(locale
+            .set_extension(ExtensionType::Other('a'), "waytoolongforatag", None)
+            .is_err());
+    }
 }
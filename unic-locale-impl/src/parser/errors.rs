@@ -0,0 +1,22 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParserError {
+    InvalidLanguage,
+    InvalidSubtag,
+    InvalidExtension,
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            ParserError::InvalidLanguage => "Invalid language subtag",
+            ParserError::InvalidSubtag => "Invalid subtag",
+            ParserError::InvalidExtension => "Invalid extension subtag",
+        };
+        f.write_str(value)
+    }
+}
+
+impl Error for ParserError {}